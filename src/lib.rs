@@ -0,0 +1,4 @@
+//! Bit banging implementations of various I/O protocols using `embedded-hal` traits
+#![no_std]
+
+pub mod serial;
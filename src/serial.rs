@@ -9,17 +9,121 @@
 //!
 
 use embedded_hal::digital::v2::{InputPin, OutputPin};
+#[cfg(feature = "eh0")]
 use embedded_hal::serial;
 use embedded_hal::timer::{CountDown, Periodic};
 use nb::block;
 
 /// Serial communication error type
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum Error<E> {
     /// Bus error
     Bus(E),
     /// Invalid interrupt call
     InvalidInterrupt,
+    /// The stop bit was not where it was expected to be
+    Framing,
+    /// Parity bit did not match the parity computed over the received data
+    Parity,
+    /// The whole frame, including the stop bit(s), was held low
+    Break,
+}
+
+/// Number of data bits carried by a frame
+///
+/// Capped at 8: the transfer API moves bytes (`embedded_hal::serial::{Read,
+/// Write}<u8>`), so a 9th data bit has nowhere to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataBits {
+    /// 5 data bits
+    Five,
+    /// 6 data bits
+    Six,
+    /// 7 data bits
+    Seven,
+    /// 8 data bits
+    Eight,
+}
+
+impl DataBits {
+    fn bits(self) -> u8 {
+        match self {
+            DataBits::Five => 5,
+            DataBits::Six => 6,
+            DataBits::Seven => 7,
+            DataBits::Eight => 8,
+        }
+    }
+}
+
+/// Parity checking mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    /// No parity bit is sent
+    None,
+    /// An even parity bit is sent
+    Even,
+    /// An odd parity bit is sent
+    Odd,
+}
+
+/// Number of stop bits sent after a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopBits {
+    /// One stop bit
+    One,
+    /// Two stop bits
+    Two,
+}
+
+impl StopBits {
+    fn count(self) -> u8 {
+        match self {
+            StopBits::One => 1,
+            StopBits::Two => 2,
+        }
+    }
+}
+
+/// Frame format configuration
+///
+/// Defaults to the 8N1 frame (8 data bits, no parity, one stop bit) that
+/// `Serial::new` has always used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    data_bits: DataBits,
+    parity: Parity,
+    stop_bits: StopBits,
+}
+
+impl Config {
+    /// Set the number of data bits
+    pub fn data_bits(mut self, data_bits: DataBits) -> Self {
+        self.data_bits = data_bits;
+        self
+    }
+
+    /// Set the parity mode
+    pub fn parity(mut self, parity: Parity) -> Self {
+        self.parity = parity;
+        self
+    }
+
+    /// Set the number of stop bits
+    pub fn stop_bits(mut self, stop_bits: StopBits) -> Self {
+        self.stop_bits = stop_bits;
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+        }
+    }
 }
 
 /// Bit banging serial communication (USART) device
@@ -32,6 +136,7 @@ where
     tx: TX,
     rx: RX,
     timer: Timer,
+    config: Config,
 }
 
 /// Reset the timer
@@ -40,120 +145,717 @@ pub trait Reset {
     fn reset(&mut self);
 }
 
-/// Nop
-pub trait Nop {
-    /// Nop
-    fn nop(&mut self);
-}
-
 impl<TX, RX, Timer, E> Serial<TX, RX, Timer>
 where
     TX: OutputPin<Error = E>,
     RX: InputPin<Error = E>,
-    Timer: CountDown + Periodic + Reset + Nop,
+    Timer: CountDown + Periodic + Reset,
 {
-    /// Create instance
+    /// Create instance, using the default 8N1 frame format
     pub fn new(tx: TX, rx: RX, timer: Timer) -> Self {
-        Serial { tx, rx, timer }
+        Self::new_with_config(tx, rx, timer, Config::default())
     }
 
-    #[inline]
-    fn reset_timer(&mut self) {
-        self.timer.reset();
+    /// Create instance with an explicit frame format
+    pub fn new_with_config(tx: TX, rx: RX, timer: Timer, config: Config) -> Self {
+        Serial {
+            tx,
+            rx,
+            timer,
+            config,
+        }
     }
 
-    #[inline]
-    fn wait_for_timer(&mut self) {
-        block!(self.timer.wait()).ok();
+    /// Split into independent transmit and receive halves
+    ///
+    /// `Serial` only owns one timer, and `Tx`/`Rx` each need to drive their
+    /// own `reset`/`wait` cycle, so a second, independent timer for the RX
+    /// half must be supplied by the caller — configured to the same 2x-baud
+    /// rate as the one already inside `self`. This lets TX and RX run from
+    /// different execution contexts (e.g. TX from the main loop, RX from an
+    /// interrupt) without contending over a single hardware timer.
+    pub fn split<RxTimer>(self, rx_timer: RxTimer) -> (Tx<TX, Timer>, Rx<RX, RxTimer>)
+    where
+        RxTimer: CountDown + Periodic + Reset,
+    {
+        (
+            Tx {
+                tx: self.tx,
+                timer: self.timer,
+                config: self.config,
+            },
+            Rx {
+                rx: self.rx,
+                timer: rx_timer,
+                config: self.config,
+            },
+        )
     }
+}
+
+#[inline]
+fn reset_timer<Timer: Reset>(timer: &mut Timer) {
+    timer.reset();
+}
+
+/// Wait for one timer tick, i.e. half a bit period
+#[inline]
+fn wait_half_bit<Timer: CountDown + Periodic>(timer: &mut Timer) {
+    block!(timer.wait()).ok();
+}
+
+/// Wait for two timer ticks, i.e. one full bit period
+#[inline]
+fn wait_full_bit<Timer: CountDown + Periodic>(timer: &mut Timer) {
+    wait_half_bit(timer);
+    wait_half_bit(timer);
+}
+
+fn write_frame<TX, Timer, E>(
+    tx: &mut TX,
+    timer: &mut Timer,
+    config: Config,
+    byte: u8,
+) -> nb::Result<(), Error<E>>
+where
+    TX: OutputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    let mut data_out = byte;
+    let mut parity = false;
+
+    tx.set_low().map_err(Error::Bus)?; // start bit
+    reset_timer(timer);
+    wait_full_bit(timer);
 
-    #[inline]
-    fn nop(&mut self) {
-        self.timer.nop();
+    for _bit in 0..config.data_bits.bits() {
+        let bit = data_out & 1 == 1;
+        parity ^= bit;
+        if bit {
+            tx.set_high().map_err(Error::Bus)?;
+        } else {
+            tx.set_low().map_err(Error::Bus)?;
+        }
+        data_out >>= 1;
+        wait_full_bit(timer);
+    }
+    match config.parity {
+        Parity::None => {}
+        Parity::Even => {
+            if parity {
+                tx.set_high().map_err(Error::Bus)?;
+            } else {
+                tx.set_low().map_err(Error::Bus)?;
+            }
+            wait_full_bit(timer);
+        }
+        Parity::Odd => {
+            if !parity {
+                tx.set_high().map_err(Error::Bus)?;
+            } else {
+                tx.set_low().map_err(Error::Bus)?;
+            }
+            wait_full_bit(timer);
+        }
+    }
+    tx.set_high().map_err(Error::Bus)?; // stop bit(s)
+    for _ in 0..config.stop_bits.count() {
+        wait_full_bit(timer);
     }
+    Ok(())
 }
 
+fn read_frame<RX, Timer, E>(
+    rx: &mut RX,
+    timer: &mut Timer,
+    config: Config,
+) -> nb::Result<u8, Error<E>>
+where
+    RX: InputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    let mut data_in: u16 = 0;
+    let mut frame_all_low = true;
+
+    // If we're currently in a high bit, then this is an invalid inturupt
+    // call. Return an `InvalidInterrupt` error.
+    if rx.is_high().map_err(Error::Bus)? {
+        return Err(nb::Error::Other(Error::InvalidInterrupt));
+    }
+
+    reset_timer(timer);
+
+    // advance to the center of the start bit and confirm it's still low
+    wait_half_bit(timer);
+    if rx.is_high().map_err(Error::Bus)? {
+        return Err(nb::Error::Other(Error::InvalidInterrupt));
+    }
+
+    let data_bits = config.data_bits.bits();
+    for _bit in 0..data_bits {
+        wait_full_bit(timer); // advance to the center of the next bit
+        data_in >>= 1;
+        if rx.is_high().map_err(Error::Bus)? {
+            data_in |= 1 << 15;
+            frame_all_low = false;
+        }
+    }
+    data_in >>= 16 - data_bits as u16;
+
+    let parity_ok = match config.parity {
+        Parity::None => true,
+        parity => {
+            wait_full_bit(timer);
+            let parity_bit = rx.is_high().map_err(Error::Bus)?;
+            if parity_bit {
+                frame_all_low = false;
+            }
+
+            let computed = (0..data_bits).fold(false, |acc, bit| acc ^ (data_in & (1 << bit) != 0));
+            let expected = match parity {
+                Parity::Even => computed,
+                Parity::Odd => !computed,
+                Parity::None => unreachable!(),
+            };
+            parity_bit == expected
+        }
+    };
+
+    // advance two ticks into each stop bit and verify the line is high
+    let mut framing_ok = true;
+    for _ in 0..config.stop_bits.count() {
+        wait_full_bit(timer);
+        if rx.is_high().map_err(Error::Bus)? {
+            frame_all_low = false;
+        } else {
+            framing_ok = false;
+        }
+    }
+
+    if !framing_ok {
+        return Err(nb::Error::Other(if frame_all_low {
+            Error::Break
+        } else {
+            Error::Framing
+        }));
+    }
+    if !parity_ok {
+        return Err(nb::Error::Other(Error::Parity));
+    }
+
+    Ok(data_in as u8)
+}
+
+#[cfg(feature = "eh0")]
 impl<TX, RX, Timer, E> serial::Write<u8> for Serial<TX, RX, Timer>
 where
     TX: OutputPin<Error = E>,
     RX: InputPin<Error = E>,
-    Timer: CountDown + Periodic + Reset + Nop,
+    Timer: CountDown + Periodic + Reset,
 {
     type Error = crate::serial::Error<E>;
 
     fn write(&mut self, byte: u8) -> nb::Result<(), Self::Error> {
-        // return Ok(());
-        let mut data_out = byte;
-        self.tx.set_low().map_err(Error::Bus)?; // start bit
-        self.reset_timer();
+        write_frame(&mut self.tx, &mut self.timer, self.config, byte)
+    }
 
-        for _ in 0..5 {
-            self.nop();
-        }
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
 
-        self.wait_for_timer();
-        for _bit in 0..8 {
-            if data_out & 1 == 1 {
-                self.tx.set_high().map_err(Error::Bus)?;
-            } else {
-                self.tx.set_low().map_err(Error::Bus)?;
-            }
-            data_out >>= 1;
-            self.wait_for_timer();
+#[cfg(feature = "eh0")]
+impl<TX, RX, Timer, E> serial::Read<u8> for Serial<TX, RX, Timer>
+where
+    TX: OutputPin<Error = E>,
+    RX: InputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    type Error = crate::serial::Error<E>;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        read_frame(&mut self.rx, &mut self.timer, self.config)
+    }
+}
+
+#[cfg(feature = "eh0")]
+impl<TX, RX, Timer, E> embedded_hal::blocking::serial::Write<u8> for Serial<TX, RX, Timer>
+where
+    TX: OutputPin<Error = E>,
+    RX: InputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    type Error = crate::serial::Error<E>;
+
+    fn bwrite_all(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        for byte in buffer {
+            block!(serial::Write::write(self, *byte))?;
         }
-        self.tx.set_high().map_err(Error::Bus)?; // stop bit
-        self.wait_for_timer();
         Ok(())
     }
 
+    fn bflush(&mut self) -> Result<(), Self::Error> {
+        block!(serial::Write::flush(self))
+    }
+}
+
+#[cfg(feature = "eh0")]
+impl<TX, RX, Timer, E> core::fmt::Write for Serial<TX, RX, Timer>
+where
+    TX: OutputPin<Error = E>,
+    RX: InputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        use embedded_hal::blocking::serial::Write as _;
+        self.bwrite_all(s.as_bytes()).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// Transmit half of a [`Serial`] produced by [`Serial::split`]
+pub struct Tx<TX, Timer>
+where
+    TX: OutputPin,
+    Timer: CountDown + Periodic,
+{
+    tx: TX,
+    timer: Timer,
+    config: Config,
+}
+
+/// Receive half of a [`Serial`] produced by [`Serial::split`]
+pub struct Rx<RX, Timer>
+where
+    RX: InputPin,
+    Timer: CountDown + Periodic,
+{
+    rx: RX,
+    timer: Timer,
+    config: Config,
+}
+
+#[cfg(feature = "eh0")]
+impl<TX, Timer, E> serial::Write<u8> for Tx<TX, Timer>
+where
+    TX: OutputPin<Error = E>,
+    Timer: CountDown + Periodic + Reset,
+{
+    type Error = crate::serial::Error<E>;
+
+    fn write(&mut self, byte: u8) -> nb::Result<(), Self::Error> {
+        write_frame(&mut self.tx, &mut self.timer, self.config, byte)
+    }
+
     fn flush(&mut self) -> nb::Result<(), Self::Error> {
         Ok(())
     }
 }
 
-impl<TX, RX, Timer, E> serial::Read<u8> for Serial<TX, RX, Timer>
+#[cfg(feature = "eh0")]
+impl<RX, Timer, E> serial::Read<u8> for Rx<RX, Timer>
 where
-    TX: OutputPin<Error = E>,
     RX: InputPin<Error = E>,
-    Timer: CountDown + Periodic + Reset + Nop,
+    Timer: CountDown + Periodic + Reset,
 {
     type Error = crate::serial::Error<E>;
 
     fn read(&mut self) -> nb::Result<u8, Self::Error> {
-        let mut data_in = 0;
+        read_frame(&mut self.rx, &mut self.timer, self.config)
+    }
+}
+
+/// Implementations of the `embedded-hal` 1.0 / `embedded-io` traits
+///
+/// These reuse the bit-bang loops above and live in this module behind the
+/// `eh1` feature, leaving the 0.2 impls above untouched for existing users.
+#[cfg(feature = "eh1")]
+mod eh1 {
+    use super::{Config, CountDown, Error, Periodic, Reset, Rx, Serial, Tx};
+    use embedded_hal::digital::v2::{InputPin, OutputPin};
+    use embedded_hal_nb::serial::{ErrorKind as Eh1ErrorKind, ErrorType};
+
+    /// Read one byte, treating an idle line (no start bit yet) as
+    /// `WouldBlock` rather than the `InvalidInterrupt` error `read_frame`
+    /// normally reports, matching the `nb` contract this trait expects.
+    fn read_byte<RX, Timer, E>(
+        rx: &mut RX,
+        timer: &mut Timer,
+        config: Config,
+    ) -> nb::Result<u8, Error<E>>
+    where
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+    {
+        match super::read_frame(rx, timer, config) {
+            Err(nb::Error::Other(Error::InvalidInterrupt)) => Err(nb::Error::WouldBlock),
+            other => other,
+        }
+    }
+
+    impl<E> embedded_hal_nb::serial::Error for Error<E>
+    where
+        E: core::fmt::Debug,
+    {
+        fn kind(&self) -> Eh1ErrorKind {
+            match self {
+                Error::Framing => Eh1ErrorKind::FrameFormat,
+                Error::Parity => Eh1ErrorKind::Parity,
+                Error::Break | Error::InvalidInterrupt => Eh1ErrorKind::Other,
+                Error::Bus(_) => Eh1ErrorKind::Other,
+            }
+        }
+    }
+
+    impl<TX, RX, Timer, E> ErrorType for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic,
+        E: core::fmt::Debug,
+    {
+        type Error = Error<E>;
+    }
+
+    impl<TX, RX, Timer, E> embedded_hal_nb::serial::Write<u8> for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+            super::write_frame(&mut self.tx, &mut self.timer, self.config, word)
+        }
+
+        fn flush(&mut self) -> nb::Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl<TX, RX, Timer, E> embedded_hal_nb::serial::Read<u8> for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self) -> nb::Result<u8, Self::Error> {
+            read_byte(&mut self.rx, &mut self.timer, self.config)
+        }
+    }
+
+    impl<TX, Timer, E> ErrorType for Tx<TX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        Timer: CountDown + Periodic,
+        E: core::fmt::Debug,
+    {
+        type Error = Error<E>;
+    }
+
+    impl<TX, Timer, E> embedded_hal_nb::serial::Write<u8> for Tx<TX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+            super::write_frame(&mut self.tx, &mut self.timer, self.config, word)
+        }
+
+        fn flush(&mut self) -> nb::Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl<RX, Timer, E> ErrorType for Rx<RX, Timer>
+    where
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic,
+        E: core::fmt::Debug,
+    {
+        type Error = Error<E>;
+    }
+
+    impl<RX, Timer, E> embedded_hal_nb::serial::Read<u8> for Rx<RX, Timer>
+    where
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self) -> nb::Result<u8, Self::Error> {
+            read_byte(&mut self.rx, &mut self.timer, self.config)
+        }
+    }
 
-        // If we're currently in a high bit, then this is an invalid inturupt
-        // call. Return a string of an error.
-        if self.rx.is_high().map_err(Error::Bus)? {
-            return Err(nb::Error::Other(Error::InvalidInterrupt));
+    fn io_error_kind(kind: Eh1ErrorKind) -> embedded_io::ErrorKind {
+        match kind {
+            Eh1ErrorKind::Parity => embedded_io::ErrorKind::InvalidData,
+            Eh1ErrorKind::FrameFormat => embedded_io::ErrorKind::InvalidData,
+            Eh1ErrorKind::Noise => embedded_io::ErrorKind::InvalidData,
+            Eh1ErrorKind::Overrun => embedded_io::ErrorKind::Other,
+            _ => embedded_io::ErrorKind::Other,
         }
+    }
 
-        // wait for start bit
-        // while self.rx.is_high().map_err(Error::Bus)? {}
-        // reset timer
+    impl<E> embedded_io::Error for Error<E>
+    where
+        E: core::fmt::Debug,
+    {
+        fn kind(&self) -> embedded_io::ErrorKind {
+            io_error_kind(embedded_hal_nb::serial::Error::kind(self))
+        }
+    }
 
-        // nop 100 times to align in the middle of the bit
-        // for _ in 0..80 {
-        //     self.nop();
-        // }
+    impl<TX, RX, Timer, E> embedded_io::ErrorType for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic,
+        E: core::fmt::Debug,
+    {
+        type Error = Error<E>;
+    }
 
-        self.reset_timer();
+    impl<TX, RX, Timer, E> embedded_io::Write for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            for byte in buf {
+                // `write_frame` always runs the bit-bang loop to completion,
+                // so it never yields `WouldBlock`.
+                if let Err(nb::Error::Other(e)) =
+                    super::write_frame(&mut self.tx, &mut self.timer, self.config, *byte)
+                {
+                    return Err(e);
+                }
+            }
+            Ok(buf.len())
+        }
 
-        for _ in 0..5 {
-            self.nop();
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
         }
+    }
+
+    impl<TX, RX, Timer, E> embedded_io::Read for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
 
-        self.wait_for_timer();
-        for _bit in 0..8 {
-            data_in >>= 1;
-            if self.rx.is_high().map_err(Error::Bus)? {
-                data_in |= 0x80;
+            // Block for at least the first byte, then opportunistically
+            // drain whatever else is already available on the line.
+            buf[0] = nb::block!(read_byte(&mut self.rx, &mut self.timer, self.config))?;
+            let mut read = 1;
+            while read < buf.len() {
+                match read_byte(&mut self.rx, &mut self.timer, self.config) {
+                    Ok(byte) => {
+                        buf[read] = byte;
+                        read += 1;
+                    }
+                    Err(nb::Error::WouldBlock) => break,
+                    Err(nb::Error::Other(e)) => return Err(e),
+                }
             }
-            self.wait_for_timer();
+            Ok(read)
+        }
+    }
+
+    impl<TX, RX, Timer, E> embedded_io::ReadReady for Serial<TX, RX, Timer>
+    where
+        TX: OutputPin<Error = E>,
+        RX: InputPin<Error = E>,
+        Timer: CountDown + Periodic + Reset,
+        E: core::fmt::Debug,
+    {
+        fn read_ready(&mut self) -> Result<bool, Self::Error> {
+            // The bit-bang receiver has no FIFO to inspect; the best we can
+            // offer is "a start bit is currently on the line".
+            self.rx.is_low().map_err(Error::Bus)
         }
-        // wait for stop bit
-        self.wait_for_timer();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+    use core::convert::Infallible;
+
+    /// A mock RX pin that replays a fixed sequence of `is_high()` samples,
+    /// one per call, in the exact order `read_frame` takes them: the idle
+    /// check, the start-bit center check, each data bit, the parity bit (if
+    /// any), then each stop bit.
+    struct SampledPin {
+        samples: [bool; 16],
+        len: usize,
+        pos: RefCell<usize>,
+    }
+
+    impl InputPin for SampledPin {
+        type Error = Infallible;
+
+        fn is_high(&self) -> Result<bool, Infallible> {
+            let mut pos = self.pos.borrow_mut();
+            assert!(
+                *pos < self.len,
+                "read_frame sampled more bits than the test provided"
+            );
+            let sample = self.samples[*pos];
+            *pos += 1;
+            Ok(sample)
+        }
+
+        fn is_low(&self) -> Result<bool, Infallible> {
+            Ok(!self.is_high()?)
+        }
+    }
+
+    /// A timer whose `wait()` never actually blocks, so `read_frame` runs to
+    /// completion immediately against the samples it's handed.
+    struct NullTimer;
+
+    impl CountDown for NullTimer {
+        type Time = u32;
+
+        fn start<T: Into<u32>>(&mut self, _count: T) {}
+
+        fn wait(&mut self) -> nb::Result<(), void::Void> {
+            Ok(())
+        }
+    }
+
+    impl Periodic for NullTimer {}
+
+    impl Reset for NullTimer {
+        fn reset(&mut self) {}
+    }
+
+    fn bits_lsb_first(byte: u8, count: u8) -> [bool; 8] {
+        let mut bits = [false; 8];
+        for (i, bit) in bits.iter_mut().enumerate().take(count as usize) {
+            *bit = (byte >> i) & 1 == 1;
+        }
+        bits
+    }
+
+    fn frame_samples(data_bits: &[bool], parity_bit: Option<bool>, stop_bits: &[bool]) -> SampledPin {
+        let mut samples = [false; 16];
+        let mut i = 0;
+        samples[i] = false; // idle check: line already low
+        i += 1;
+        samples[i] = false; // start-bit center: still low
+        i += 1;
+        for &bit in data_bits {
+            samples[i] = bit;
+            i += 1;
+        }
+        if let Some(bit) = parity_bit {
+            samples[i] = bit;
+            i += 1;
+        }
+        for &bit in stop_bits {
+            samples[i] = bit;
+            i += 1;
+        }
+        SampledPin {
+            samples,
+            len: i,
+            pos: RefCell::new(0),
+        }
+    }
+
+    fn even_parity(bits: &[bool]) -> bool {
+        bits.iter().fold(false, |acc, &bit| acc ^ bit)
+    }
+
+    #[test]
+    fn even_parity_ok() {
+        let byte = 0b1010_1010u8;
+        let bits = bits_lsb_first(byte, 8);
+        let mut rx = frame_samples(&bits, Some(even_parity(&bits)), &[true]);
+        let mut timer = NullTimer;
+        let config = Config::default().parity(Parity::Even);
+        assert_eq!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Ok(byte)
+        );
+    }
+
+    #[test]
+    fn odd_parity_ok() {
+        let byte = 0b0110_0110u8;
+        let bits = bits_lsb_first(byte, 8);
+        let mut rx = frame_samples(&bits, Some(!even_parity(&bits)), &[true]);
+        let mut timer = NullTimer;
+        let config = Config::default().parity(Parity::Odd);
+        assert_eq!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Ok(byte)
+        );
+    }
+
+    #[test]
+    fn parity_mismatch_is_rejected() {
+        let byte = 0b1010_1010u8;
+        let bits = bits_lsb_first(byte, 8);
+        // Flip the parity bit so it no longer matches the data.
+        let mut rx = frame_samples(&bits, Some(!even_parity(&bits)), &[true]);
+        let mut timer = NullTimer;
+        let config = Config::default().parity(Parity::Even);
+        assert!(matches!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Err(nb::Error::Other(Error::Parity))
+        ));
+    }
+
+    #[test]
+    fn low_stop_bit_is_framing_error() {
+        let bits = bits_lsb_first(0b0000_1111, 8);
+        // Stop bit sampled low, but the data wasn't all low, so this is a
+        // plain framing violation rather than a break condition.
+        let mut rx = frame_samples(&bits, None, &[false]);
+        let mut timer = NullTimer;
+        let config = Config::default();
+        assert!(matches!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Err(nb::Error::Other(Error::Framing))
+        ));
+    }
+
+    #[test]
+    fn all_low_frame_is_break() {
+        let bits = [false; 8];
+        let mut rx = frame_samples(&bits, None, &[false]);
+        let mut timer = NullTimer;
+        let config = Config::default();
+        assert!(matches!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Err(nb::Error::Other(Error::Break))
+        ));
+    }
 
-        Ok(data_in)
+    #[test]
+    fn five_bit_frame_round_trip() {
+        let byte = 0b0001_0101u8;
+        let bits = bits_lsb_first(byte, 5);
+        let mut rx = frame_samples(&bits[..5], None, &[true]);
+        let mut timer = NullTimer;
+        let config = Config::default().data_bits(DataBits::Five);
+        assert_eq!(
+            read_frame::<_, _, Infallible>(&mut rx, &mut timer, config),
+            Ok(byte)
+        );
     }
 }